@@ -0,0 +1,161 @@
+//! 節氣、合朔等天文計算共用的底層函式，供 `solar_term` 及 `lunar_date` 模組使用。
+
+use chrono::Datelike;
+use chrono::NaiveDate;
+
+/// 朔望月平均長度(天)。
+const SYNODIC_MONTH: f64 = 29.530588861;
+
+/// 將 `NaiveDate` 午夜(UTC)轉成儒略日。
+pub(crate) fn julian_day_from_naive_date(date: NaiveDate) -> f64 {
+    f64::from(date.num_days_from_ce()) + 1721424.5
+}
+
+/// 將儒略日轉成 `NaiveDate`(以 UTC 為準，僅保留日期部分)。
+pub(crate) fn julian_day_to_naive_date(jd: f64) -> NaiveDate {
+    let days_from_ce = (jd - 1721424.5).floor() as i32;
+
+    NaiveDate::from_num_days_from_ce(days_from_ce)
+}
+
+/// 將儒略日轉成中國標準時間(UTC+8)下的 `NaiveDate`。
+pub(crate) fn naive_date_in_cst(jd: f64) -> NaiveDate {
+    julian_day_to_naive_date(jd + 8.0 / 24.0)
+}
+
+/// 將角度正規化至 `[0, 360)` 範圍內。
+pub(crate) fn normalize_degrees(degrees: f64) -> f64 {
+    let normalized = degrees % 360.0;
+
+    if normalized < 0.0 {
+        normalized + 360.0
+    } else {
+        normalized
+    }
+}
+
+/// `longitude` 與 `target` 的帶號角度差，範圍 `[-180, 180)`，用來判斷角度是否已越過目標值。
+pub(crate) fn signed_longitude_diff(longitude: f64, target: f64) -> f64 {
+    normalize_degrees(longitude - target + 180.0) - 180.0
+}
+
+/// 太陽的視黃經(單位:度，範圍 0 至 360)，`jd` 為儒略日。
+pub(crate) fn solar_apparent_longitude(jd: f64) -> f64 {
+    let t = (jd - 2451545.0) / 36525.0;
+
+    let l0 = 280.46646 + 36000.76983 * t + 0.0003032 * t * t;
+    let m = (357.52911 + 35999.05029 * t - 0.0001537 * t * t).to_radians();
+
+    let c = (1.914602 - 0.004817 * t - 0.000014 * t * t) * m.sin()
+        + (0.019993 - 0.000101 * t) * (2.0 * m).sin()
+        + 0.000289 * (3.0 * m).sin();
+
+    let true_longitude = l0 + c;
+
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let apparent_longitude = true_longitude - 0.00569 - 0.00478 * omega.sin();
+
+    normalize_degrees(apparent_longitude)
+}
+
+/// 月球的視黃經(單位:度，範圍 0 至 360)，`jd` 為儒略日，採 Meeus 簡化級數，精度約 0.01 度。
+pub(crate) fn moon_apparent_longitude(jd: f64) -> f64 {
+    let t = (jd - 2451545.0) / 36525.0;
+
+    let l = 218.3164591 + 481267.88134236 * t - 0.0013268 * t * t;
+    let d = (297.8502042 + 445267.1115168 * t - 0.0016300 * t * t).to_radians();
+    let m = (357.5291092 + 35999.0502909 * t - 0.0001536 * t * t).to_radians();
+    let m_prime = (134.9634114 + 477198.8676313 * t + 0.0089970 * t * t).to_radians();
+    let f = (93.2720993 + 483202.0175273 * t - 0.0034029 * t * t).to_radians();
+
+    let delta_l = 6.288774 * m_prime.sin()
+        + 1.274027 * (2.0 * d - m_prime).sin()
+        + 0.658314 * (2.0 * d).sin()
+        + 0.213618 * (2.0 * m_prime).sin()
+        - 0.185116 * m.sin()
+        - 0.114332 * (2.0 * f).sin()
+        + 0.058793 * (2.0 * d - 2.0 * m_prime).sin()
+        + 0.057066 * (2.0 * d - m - m_prime).sin()
+        + 0.053322 * (2.0 * d + m_prime).sin()
+        + 0.045758 * (2.0 * d - m).sin()
+        - 0.040923 * (m - m_prime).sin()
+        - 0.034720 * d.sin()
+        - 0.030383 * (m + m_prime).sin()
+        + 0.015327 * (2.0 * d - 2.0 * f).sin()
+        - 0.012528 * (m_prime + 2.0 * f).sin()
+        - 0.010980 * (m_prime - 2.0 * f).sin()
+        + 0.010675 * (4.0 * d - m_prime).sin()
+        + 0.010034 * (3.0 * m_prime).sin()
+        + 0.008548 * (4.0 * d - 2.0 * m_prime).sin()
+        - 0.007888 * (2.0 * d + m - m_prime).sin()
+        - 0.006766 * (2.0 * d + m).sin();
+
+    normalize_degrees(l + delta_l)
+}
+
+/// 月球與太陽的黃經差(朔望角)，為 0 時即為合朔(新月)。
+fn lunar_elongation(jd: f64) -> f64 {
+    normalize_degrees(moon_apparent_longitude(jd) - solar_apparent_longitude(jd))
+}
+
+/// 取得 `jd` 所屬的朔望月序(以 2000 年 1 月 6 日的新月為 k = 0)，取整數部分。
+pub(crate) fn k_for_new_moon(jd: f64) -> f64 {
+    ((jd - 2451550.09766) / SYNODIC_MONTH).floor()
+}
+
+/// 以二分搜尋找出第 `k` 個朔望月序所對應的合朔(新月)儒略日。
+pub(crate) fn new_moon_near_k(k: f64) -> f64 {
+    let t = k / 1236.85;
+
+    let estimate = 2451550.09766 + SYNODIC_MONTH * k + 0.00015437 * t * t - 0.000000150 * t * t * t
+        + 0.00000000073 * t * t * t * t;
+
+    let mut lo = estimate - 1.5;
+    let mut hi = estimate + 1.5;
+
+    if signed_longitude_diff(lunar_elongation(lo), 0.0) > 0.0 {
+        lo -= SYNODIC_MONTH;
+    }
+
+    if signed_longitude_diff(lunar_elongation(hi), 0.0) < 0.0 {
+        hi += SYNODIC_MONTH;
+    }
+
+    for _ in 0..50 {
+        let mid = (lo + hi) / 2.0;
+
+        if signed_longitude_diff(lunar_elongation(mid), 0.0) < 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    (lo + hi) / 2.0
+}
+
+/// 找出在 `after_jd` 之後，太陽視黃經第一次到達 30 度倍數(中氣)的儒略日。
+pub(crate) fn next_major_term_jd(after_jd: f64) -> f64 {
+    let current_longitude = solar_apparent_longitude(after_jd);
+    let target = normalize_degrees((current_longitude / 30.0).floor() * 30.0 + 30.0);
+
+    let mut lo = after_jd;
+    let mut hi = after_jd + 45.0;
+
+    while signed_longitude_diff(solar_apparent_longitude(hi), target) < 0.0 {
+        lo = hi;
+        hi += 45.0;
+    }
+
+    for _ in 0..50 {
+        let mid = (lo + hi) / 2.0;
+
+        if signed_longitude_diff(solar_apparent_longitude(mid), target) < 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    (lo + hi) / 2.0
+}
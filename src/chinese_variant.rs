@@ -0,0 +1,6 @@
+/// 繁體中文或簡體中文。
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum ChineseVariant {
+    Traditional,
+    Simple,
+}
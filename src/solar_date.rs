@@ -107,9 +107,84 @@ impl SolarDate {
         Self::from_date(Utc::now().date())
     }
 
+    /// 依照 `fmt` 所描述的版面來解析西曆日期字串，`fmt` 支援 `yyyy`、`mm`、`dd` 代碼(分別代表年、月、日)，其餘字元(包含中文的「年」、「月」、「日」)皆視為必須逐字相符的分隔符。
+    pub fn parse_from_str<S: AsRef<str>>(s: S, fmt: &str) -> Result<SolarDate, SolarDateParseError> {
+        let mut s = s.as_ref();
+        let mut fmt = fmt;
+
+        let mut year: Option<u16> = None;
+        let mut month: Option<u8> = None;
+        let mut day: Option<u8> = None;
+
+        while !fmt.is_empty() {
+            if fmt.starts_with("yyyy") {
+                let (value, rest) = take_digits(s, 4).ok_or(SolarDateParseError::IncorrectYear)?;
+
+                year = Some(value as u16);
+                s = rest;
+                fmt = &fmt[4..];
+            } else if fmt.starts_with("mm") {
+                let (value, rest) = take_digits(s, 2).ok_or(SolarDateParseError::IncorrectMonth)?;
+
+                month = Some(value as u8);
+                s = rest;
+                fmt = &fmt[2..];
+            } else if fmt.starts_with("dd") {
+                let (value, rest) = take_digits(s, 2).ok_or(SolarDateParseError::IncorrectDay)?;
+
+                day = Some(value as u8);
+                s = rest;
+                fmt = &fmt[2..];
+            } else {
+                let fmt_char = fmt.chars().next().unwrap();
+                let s_char = s.chars().next().ok_or_else(|| next_field_error(year, month))?;
+
+                if s_char != fmt_char {
+                    return Err(next_field_error(year, month));
+                }
+
+                s = &s[s_char.len_utf8()..];
+                fmt = &fmt[fmt_char.len_utf8()..];
+            }
+        }
+
+        if !s.is_empty() {
+            return Err(SolarDateParseError::IncorrectDay);
+        }
+
+        let year = year.ok_or(SolarDateParseError::IncorrectYear)?;
+        let month = month.ok_or(SolarDateParseError::IncorrectMonth)?;
+        let day = day.ok_or(SolarDateParseError::IncorrectDay)?;
+
+        Self::from_ymd(year, month, day)
+    }
+
+    /// 依序嘗試內建的版面(中文的「年月日」格式、`yyyy-mm-dd`、`yyyy/mm/dd`、`yyyymmdd`)來解析西曆日期字串，並傳回第一個解析成功的結果。
     pub fn from_str<S: AsRef<str>>(s: S) -> Result<SolarDate, SolarDateParseError> {
         let s = s.as_ref();
 
+        let chinese_result = Self::from_chinese_str(s);
+
+        if chinese_result.is_ok() {
+            return chinese_result;
+        }
+
+        const BUILTIN_FORMATS: [&str; 3] = ["yyyy-mm-dd", "yyyy/mm/dd", "yyyymmdd"];
+
+        let mut last_err = chinese_result.unwrap_err();
+
+        for fmt in BUILTIN_FORMATS.iter() {
+            match Self::parse_from_str(s, fmt) {
+                Ok(solar_date) => return Ok(solar_date),
+                Err(err) => last_err = err
+            }
+        }
+
+        Err(last_err)
+    }
+
+    /// 以中文的「年月日」版面來解析西曆日期字串。
+    fn from_chinese_str(s: &str) -> Result<SolarDate, SolarDateParseError> {
         let year_index = {
             match s.find("年") {
                 Some(index) => index,
@@ -192,10 +267,126 @@ impl SolarDate {
     pub fn get_solar_day(&self) -> SolarDay {
         self.solar_day
     }
+
+    /// 取得這個 `SolarDate` 實體往後推 `days` 天的 `SolarDate` 實體；若加總後的天數超出 `i32` 所能表示
+    /// 的範圍(遠大於任何合理的日期範圍)，則傳回 `OutOfRange` 而非直接 `panic`。
+    pub fn add_days(self, days: i64) -> Result<SolarDate, SolarDateParseError> {
+        let days_from_ce = i64::from(self.to_naive_date().num_days_from_ce());
+
+        let target_days_from_ce = match days_from_ce.checked_add(days) {
+            Some(value) if value >= i64::from(i32::min_value()) && value <= i64::from(i32::max_value()) => value as i32,
+            _ => return Err(SolarDateParseError::OutOfRange),
+        };
+
+        match NaiveDate::from_num_days_from_ce_opt(target_days_from_ce) {
+            Some(naive_date) => Self::from_naive_date(naive_date),
+            None => Err(SolarDateParseError::OutOfRange),
+        }
+    }
+
+    /// 取得這個 `SolarDate` 實體往前推 `days` 天的 `SolarDate` 實體。
+    pub fn sub_days(self, days: i64) -> Result<SolarDate, SolarDateParseError> {
+        self.add_days(-days)
+    }
+
+    /// 取得這個 `SolarDate` 實體與 `other` 相差的天數，當這個實體較晚時為正數。
+    pub fn signed_days_since(&self, other: &SolarDate) -> i64 {
+        self.to_naive_date().signed_duration_since(other.to_naive_date()).num_days()
+    }
+
+    /// 以這個 `SolarDate` 實體為起點(含)，`end` 為終點(不含)，逐日疊代 `[self, end)` 區間內的每一天。
+    pub fn iter_until(self, end: SolarDate) -> SolarDateRange {
+        SolarDateRange {
+            current: self,
+            end,
+        }
+    }
+}
+
+/// 由 `SolarDate::iter_until` 所產生的逐日疊代器，範圍為左閉右開的 `[start, end)`。
+#[derive(Debug, Clone)]
+pub struct SolarDateRange {
+    current: SolarDate,
+    end: SolarDate,
+}
+
+impl Iterator for SolarDateRange {
+    type Item = SolarDate;
+
+    fn next(&mut self) -> Option<SolarDate> {
+        if self.current == self.end || self.current.signed_days_since(&self.end) > 0 {
+            return None;
+        }
+
+        let current = self.current;
+
+        self.current = current.add_days(1).unwrap_or(self.end);
+
+        Some(current)
+    }
 }
 
 impl Display for SolarDate {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         f.write_str(&self.to_chinese_string())
     }
-}
\ No newline at end of file
+}
+
+/// 依目前已解析出的欄位，判斷分隔符不相符時應回報哪一個欄位的錯誤：回報下一個尚未解析出的欄位。
+fn next_field_error(year: Option<u16>, month: Option<u8>) -> SolarDateParseError {
+    if year.is_none() {
+        SolarDateParseError::IncorrectYear
+    } else if month.is_none() {
+        SolarDateParseError::IncorrectMonth
+    } else {
+        SolarDateParseError::IncorrectDay
+    }
+}
+
+/// 從 `s` 的開頭貪婪地讀取最多 `max_width` 個阿拉伯數字字元，傳回讀到的數值與剩餘的字串。
+fn take_digits(s: &str, max_width: usize) -> Option<(u32, &str)> {
+    let digit_count = s.chars().take(max_width).take_while(|c| c.is_ascii_digit()).count();
+
+    if digit_count == 0 {
+        return None;
+    }
+
+    let (digits, rest) = s.split_at(digit_count);
+
+    digits.parse::<u32>().ok().map(|value| (value, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_from_str_rejects_trailing_garbage() {
+        assert!(SolarDate::parse_from_str("2024-01-01GARBAGE", "yyyy-mm-dd").is_err());
+    }
+
+    #[test]
+    fn from_str_rejects_trailing_garbage() {
+        assert!(SolarDate::from_str("20240101trailing").is_err());
+    }
+
+    #[test]
+    fn parse_from_str_still_accepts_exact_match() {
+        assert_eq!(SolarDate::parse_from_str("2024-01-01", "yyyy-mm-dd").unwrap(), SolarDate::from_ymd(2024, 1, 1).unwrap());
+    }
+
+    #[test]
+    fn add_days_reports_out_of_range_instead_of_panicking() {
+        let date = SolarDate::from_ymd(2024, 1, 1).unwrap();
+
+        assert_eq!(date.add_days(i64::max_value()), Err(SolarDateParseError::OutOfRange));
+        assert_eq!(date.add_days(i64::min_value()), Err(SolarDateParseError::OutOfRange));
+    }
+
+    #[test]
+    fn add_days_still_works_for_ordinary_offsets() {
+        let date = SolarDate::from_ymd(2024, 1, 1).unwrap();
+
+        assert_eq!(date.add_days(1).unwrap(), SolarDate::from_ymd(2024, 1, 2).unwrap());
+    }
+}
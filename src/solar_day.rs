@@ -0,0 +1,45 @@
+use std::fmt::{self, Display, Formatter};
+
+const DAY_STR: [&str; 31] = [
+    "一", "二", "三", "四", "五", "六", "七", "八", "九", "十",
+    "十一", "十二", "十三", "十四", "十五", "十六", "十七", "十八", "十九", "二十",
+    "廿一", "廿二", "廿三", "廿四", "廿五", "廿六", "廿七", "廿八", "廿九", "三十", "三十一",
+];
+
+/// 西曆日。
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord)]
+pub struct SolarDay(u8);
+
+impl SolarDay {
+    /// 利用 `u8` 整數來產生 `SolarDay` 實體，`day` 必須界於 1 至 31 之間。
+    pub fn from_u8(day: u8) -> Option<SolarDay> {
+        if day >= 1 && day <= 31 {
+            Some(SolarDay(day))
+        } else {
+            None
+        }
+    }
+
+    /// 取得 `SolarDay` 實體所代表的 `u8` 整數。
+    pub fn to_u8(self) -> u8 {
+        self.0
+    }
+
+    /// 取得 `SolarDay` 實體所代表的中文日期字串(不含「日」字)。
+    pub fn to_str(self) -> &'static str {
+        DAY_STR[(self.0 - 1) as usize]
+    }
+
+    /// 利用中文日期字串來產生 `SolarDay` 實體。
+    pub fn from_str<S: AsRef<str>>(s: S) -> Option<SolarDay> {
+        let s = s.as_ref();
+
+        DAY_STR.iter().position(|&d| d == s).map(|i| SolarDay((i + 1) as u8))
+    }
+}
+
+impl Display for SolarDay {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str(self.to_str())
+    }
+}
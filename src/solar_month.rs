@@ -0,0 +1,44 @@
+use std::fmt::{self, Display, Formatter};
+
+const MONTH_STR: [&str; 12] = [
+    "一月", "二月", "三月", "四月", "五月", "六月",
+    "七月", "八月", "九月", "十月", "十一月", "十二月",
+];
+
+/// 西曆月。
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord)]
+pub struct SolarMonth(u8);
+
+impl SolarMonth {
+    /// 利用 `u8` 整數來產生 `SolarMonth` 實體，`month` 必須界於 1 至 12 之間。
+    pub fn from_u8(month: u8) -> Option<SolarMonth> {
+        if month >= 1 && month <= 12 {
+            Some(SolarMonth(month))
+        } else {
+            None
+        }
+    }
+
+    /// 取得 `SolarMonth` 實體所代表的 `u8` 整數。
+    pub fn to_u8(self) -> u8 {
+        self.0
+    }
+
+    /// 取得 `SolarMonth` 實體所代表的中文月份字串。
+    pub fn to_str(self) -> &'static str {
+        MONTH_STR[(self.0 - 1) as usize]
+    }
+
+    /// 利用中文月份字串來產生 `SolarMonth` 實體。
+    pub fn from_str<S: AsRef<str>>(s: S) -> Option<SolarMonth> {
+        let s = s.as_ref();
+
+        MONTH_STR.iter().position(|&m| m == s).map(|i| SolarMonth((i + 1) as u8))
+    }
+}
+
+impl Display for SolarMonth {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str(self.to_str())
+    }
+}
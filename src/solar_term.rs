@@ -0,0 +1,155 @@
+use std::fmt::{self, Display, Formatter};
+
+use chrono::NaiveDate;
+
+use super::astro::{
+    julian_day_from_naive_date, naive_date_in_cst, normalize_degrees,
+    signed_longitude_diff, solar_apparent_longitude,
+};
+use super::{SolarDate, SolarYear};
+
+const TERM_NAMES: [&str; 24] = [
+    "春分", "清明", "穀雨", "立夏", "小滿", "芒種",
+    "夏至", "小暑", "大暑", "立秋", "處暑", "白露",
+    "秋分", "寒露", "霜降", "立冬", "小雪", "大雪",
+    "冬至", "小寒", "大寒", "立春", "雨水", "驚蟄",
+];
+
+/// 二十四節氣。太陽視黃經每增加 15 度便是下一個節氣，以春分(0 度)為起點。
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum SolarTerm {
+    ChunFen,
+    QingMing,
+    GuYu,
+    LiXia,
+    XiaoMan,
+    MangZhong,
+    XiaZhi,
+    XiaoShu,
+    DaShu,
+    LiQiu,
+    ChuShu,
+    BaiLu,
+    QiuFen,
+    HanLu,
+    ShuangJiang,
+    LiDong,
+    XiaoXue,
+    DaXue,
+    DongZhi,
+    XiaoHan,
+    DaHan,
+    LiChun,
+    YuShui,
+    JingZhe,
+}
+
+const TERMS: [SolarTerm; 24] = {
+    use SolarTerm::*;
+
+    [
+        ChunFen, QingMing, GuYu, LiXia, XiaoMan, MangZhong,
+        XiaZhi, XiaoShu, DaShu, LiQiu, ChuShu, BaiLu,
+        QiuFen, HanLu, ShuangJiang, LiDong, XiaoXue, DaXue,
+        DongZhi, XiaoHan, DaHan, LiChun, YuShui, JingZhe,
+    ]
+};
+
+impl SolarTerm {
+    /// 取得這個節氣的索引(0 至 23)，索引乘以 15 即為太陽視黃經的度數。
+    pub fn index(self) -> u8 {
+        TERMS.iter().position(|&term| term == self).unwrap() as u8
+    }
+
+    /// 利用索引(0 至 23)來取得對應的 `SolarTerm`。
+    pub fn from_index(index: u8) -> SolarTerm {
+        TERMS[(index % 24) as usize]
+    }
+
+    /// 取得這個節氣所對應的太陽視黃經度數(0、15、30、...、345)。
+    pub fn longitude(self) -> f64 {
+        f64::from(self.index()) * 15.0
+    }
+
+    /// 取得這個節氣在指定西曆年中開始的 `SolarDate`。
+    pub fn to_solar_date(self, year: SolarYear) -> SolarDate {
+        let jd = find_term_julian_day(i32::from(year.to_u16()), self.longitude());
+        let naive_date = naive_date_in_cst(jd);
+
+        SolarDate::from_naive_date(naive_date).unwrap()
+    }
+}
+
+impl Display for SolarTerm {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str(TERM_NAMES[self.index() as usize])
+    }
+}
+
+impl SolarDate {
+    /// 取得這個西曆日期所對應的節氣；若這一天並非某個節氣的交節日，則傳回 `None`。
+    pub fn get_solar_term(&self) -> Option<SolarTerm> {
+        let year = self.get_solar_year();
+
+        TERMS.iter().copied().find(|&term| term.to_solar_date(year) == *self)
+    }
+}
+
+/// 在指定西曆年中，以二分搜尋找出太陽視黃經抵達 `target_longitude` 的儒略日。
+fn find_term_julian_day(year: i32, target_longitude: f64) -> f64 {
+    // 太陽視黃經每天約前進 0.9856 度，先據此估計交節日期，再以一個遠小於一整年的視窗
+    // 二分搜尋精確化，避免搜尋視窗涵蓋超過一整圈黃經而誤判交會點。
+    let jan1 = julian_day_from_naive_date(NaiveDate::from_ymd(year, 1, 1));
+    let degrees_to_advance = normalize_degrees(target_longitude - solar_apparent_longitude(jan1));
+    let estimate = jan1 + degrees_to_advance / 0.9856474;
+
+    let mut lo = estimate - 10.0;
+    let mut hi = estimate + 10.0;
+
+    while signed_longitude_diff(solar_apparent_longitude(lo), target_longitude) > 0.0 {
+        lo -= 10.0;
+    }
+
+    while signed_longitude_diff(solar_apparent_longitude(hi), target_longitude) < 0.0 {
+        hi += 10.0;
+    }
+
+    for _ in 0..50 {
+        let mid = (lo + hi) / 2.0;
+
+        if signed_longitude_diff(solar_apparent_longitude(mid), target_longitude) < 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    (lo + hi) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xia_zhi_2024_is_in_cst() {
+        // 2024 年夏至的準確交節時刻為 2024-06-21 04:51(UTC+8)，换算 UTC 後已跨過午夜，
+        // 若誤用 UTC 儒略日轉換會得到前一天。
+        assert_eq!(
+            SolarTerm::XiaZhi.to_solar_date(SolarYear::from_u16(2024)).to_naive_date(),
+            NaiveDate::from_ymd(2024, 6, 21)
+        );
+    }
+
+    #[test]
+    fn dong_zhi_known_dates() {
+        assert_eq!(
+            SolarTerm::DongZhi.to_solar_date(SolarYear::from_u16(2022)).to_naive_date(),
+            NaiveDate::from_ymd(2022, 12, 22)
+        );
+        assert_eq!(
+            SolarTerm::DongZhi.to_solar_date(SolarYear::from_u16(2026)).to_naive_date(),
+            NaiveDate::from_ymd(2026, 12, 22)
+        );
+    }
+}
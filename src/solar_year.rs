@@ -0,0 +1,57 @@
+use std::fmt::{self, Display, Formatter};
+
+const CHINESE_DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+/// 西曆年。
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord)]
+pub struct SolarYear(u16);
+
+impl SolarYear {
+    /// 利用 `u16` 整數來產生 `SolarYear` 實體。
+    pub fn from_u16(year: u16) -> SolarYear {
+        SolarYear(year)
+    }
+
+    /// 取得 `SolarYear` 實體所代表的 `u16` 整數。
+    pub fn to_u16(self) -> u16 {
+        self.0
+    }
+
+    /// 利用字串來產生 `SolarYear` 實體，接受阿拉伯數字或中文數字(例如「二〇二四」)。
+    pub fn from_str<S: AsRef<str>>(s: S) -> Option<SolarYear> {
+        let s = s.as_ref();
+
+        if let Ok(year) = s.parse::<u16>() {
+            return Some(SolarYear(year));
+        }
+
+        let mut year: u16 = 0;
+
+        for c in s.chars() {
+            let digit = CHINESE_DIGITS.iter().position(|&d| d == c)? as u16;
+            year = year.checked_mul(10)?.checked_add(digit)?;
+        }
+
+        Some(SolarYear(year))
+    }
+
+    /// 將 `SolarYear` 實體所代表的中文年份字串寫入一個 `String`。
+    pub fn write_to_chinese_string(&self, s: &mut String) {
+        for c in self.0.to_string().chars() {
+            let digit = c.to_digit(10).unwrap() as usize;
+            s.push(CHINESE_DIGITS[digit]);
+        }
+    }
+}
+
+impl From<u16> for SolarYear {
+    fn from(year: u16) -> SolarYear {
+        SolarYear(year)
+    }
+}
+
+impl Display for SolarYear {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        Display::fmt(&self.0, f)
+    }
+}